@@ -8,10 +8,21 @@
 //! - Use [`unwrap_message!`](unwrap_message) to provide context.
 //! - Use [`unwrap_format!`](unwrap_format) to have more control over the message's format.
 //! - Use [`unwrap`](unwrap) / [`unwrap_fatal`](UnwrapExt::unwrap_fatal) to report the error when context is provided/obvious.
+//! - Use [`unwrap_chain`](unwrap_chain) / [`unwrap_chain_fatal`](UnwrapChainExt::unwrap_chain_fatal) to also print the [`source`](std::error::Error::source) chain of layered errors.
 //!
 //! For aborting:
 //! - Use [`error!`](error) to report context + error.
 //! - Use [`fatal!`](fatal) when [`error!`](error)'s prefix is unwelcome.
+//! - Use [`bug!`](bug) / [`unwrap_bug!`](unwrap_bug) instead of [`error!`](error) when the failure indicates a bug in this code rather than bad user input.
+//! - Use the `_code` variants ([`fatal_code!`](fatal_code), [`error_code!`](error_code), [`expect_code`](expect_code)) to exit with a chosen code, e.g. one of the `EX_*` sysexits constants.
+//!
+//! With the `color` feature enabled, [`error!`](error)/[`error_code!`](error_code) color the whole message
+//! (not just the prefix), honoring `NO_COLOR`/`CLICOLOR_FORCE` and TTY detection; call
+//! [`set_color_choice`](set_color_choice) to override detection globally.
+//!
+//! For batch validation (report every failure instead of aborting on the first):
+//! - Use [`Collector`](Collector) to accumulate [`Result`](Result)s as you go.
+//! - Use [`unwrap_all`](unwrap_all) to unwrap a whole iterator of [`Result`](Result)s at once.
 //!
 //! # (Pseudo-)Example:
 //! ```ignore
@@ -28,35 +39,153 @@
 
 use std::fmt::Display;
 
+/// Exit code constants mirroring the BSD `sysexits.h` convention, for use with [`fatal_code!`](fatal_code)
+/// / [`error_code!`](error_code) / [`expect_code`](expect_code).
+///
+/// E.g. `error_code!(fatal::EX_USAGE, "bad flag {}", flag)`.
+pub const EX_USAGE: i32 = 64;
+/// See [`EX_USAGE`](EX_USAGE).
+pub const EX_DATAERR: i32 = 65;
+/// See [`EX_USAGE`](EX_USAGE).
+pub const EX_NOINPUT: i32 = 66;
+/// See [`EX_USAGE`](EX_USAGE).
+pub const EX_NOUSER: i32 = 67;
+/// See [`EX_USAGE`](EX_USAGE).
+pub const EX_NOHOST: i32 = 68;
+/// See [`EX_USAGE`](EX_USAGE).
+pub const EX_UNAVAILABLE: i32 = 69;
+/// See [`EX_USAGE`](EX_USAGE).
+pub const EX_SOFTWARE: i32 = 70;
+/// See [`EX_USAGE`](EX_USAGE).
+pub const EX_OSERR: i32 = 71;
+/// See [`EX_USAGE`](EX_USAGE).
+pub const EX_OSFILE: i32 = 72;
+/// See [`EX_USAGE`](EX_USAGE).
+pub const EX_CANTCREAT: i32 = 73;
+/// See [`EX_USAGE`](EX_USAGE).
+pub const EX_IOERR: i32 = 74;
+/// See [`EX_USAGE`](EX_USAGE).
+pub const EX_TEMPFAIL: i32 = 75;
+/// See [`EX_USAGE`](EX_USAGE).
+pub const EX_PROTOCOL: i32 = 76;
+/// See [`EX_USAGE`](EX_USAGE).
+pub const EX_NOPERM: i32 = 77;
+/// See [`EX_USAGE`](EX_USAGE).
+pub const EX_CONFIG: i32 = 78;
+
+#[doc(hidden)]
+/// Writes formatted output to a locked [`stderr`](::std::io::stderr), tolerating write failures instead of
+/// panicking the way [`eprintln!`](::std::eprintln) would.
+///
+/// The most common failure here is [`BrokenPipe`](::std::io::ErrorKind::BrokenPipe), e.g. when a CLI is piped
+/// into `head` and the reader closes early. We're already on our way out via [`process::exit`](::std::process::exit),
+/// so a broken pipe — or any other write error — is simply swallowed rather than left to unwind.
+pub fn internal_write_stderr(args: std::fmt::Arguments) {
+  use std::io::Write;
+  std::io::stderr().lock().write_fmt(args)
+    .ok(); // ignore any potential error (e.g. a broken pipe), we're already exiting.
+}
+
 #[macro_export]
-/// Prints to standard-error and exits with an error-code. Returns [`!`](https://doc.rust-lang.org/std/primitive.never.html).
+/// Prints to standard-error and exits with the given error-code. Returns [`!`](https://doc.rust-lang.org/std/primitive.never.html).
 ///
-/// Equivalent to [`eprintln!`](::std::eprintln) followed by [`process::exit`](::std::process::exit).
-macro_rules! fatal {
-  () => { ::std::process::exit(1) };
-  ($($arg:tt)*) => {
+/// Equivalent to [`eprintln!`](::std::eprintln) followed by [`process::exit`](::std::process::exit) with the given code,
+/// except that a failed write (e.g. a broken pipe) is swallowed instead of panicking.
+///
+/// See [`fatal!`](fatal) for a version that defaults to exit code `1`.
+macro_rules! fatal_code {
+  ($code:expr) => { ::std::process::exit($code) };
+  ($code:expr, $($arg:tt)*) => {
     {
-      ::std::eprintln!($($arg)*);
-      $crate::fatal!()
+      $crate::internal_write_stderr(::std::format_args!($($arg)*));
+      $crate::internal_write_stderr(::std::format_args!("\n"));
+      $crate::fatal_code!($code)
      }
   };
 }
 
+#[macro_export]
+/// Prints to standard-error and exits with error-code `1`. Returns [`!`](https://doc.rust-lang.org/std/primitive.never.html).
+///
+/// Equivalent to [`eprintln!`](::std::eprintln) followed by [`process::exit`](::std::process::exit),
+/// except that a failed write (e.g. a broken pipe) is swallowed instead of panicking.
+///
+/// See [`fatal_code!`](fatal_code) to choose a different exit code.
+macro_rules! fatal {
+  () => { $crate::fatal_code!(1) };
+  ($($arg:tt)*) => { $crate::fatal_code!(1, $($arg)*) };
+}
+
 /// Yields the error prefix string.
 ///
 /// This is a macro to minimize code generation (compared to a `println!("{}", ERROR_PREFIX_CONST)`).
 macro_rules! get_error_prefix { () => {"Error: "} }
 
+#[cfg(feature = "color")]
+use std::sync::atomic::{AtomicU8, Ordering};
+
+#[cfg(feature = "color")]
+/// Mirrors [`termcolor::ColorChoice`](termcolor::ColorChoice), collapsed to the three choices an application
+/// actually needs in order to override this crate's automatic detection. See [`set_color_choice`](set_color_choice).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorChoice {
+  /// Always colorize, regardless of environment variables or whether stderr is a terminal.
+  Always,
+  /// Never colorize.
+  Never,
+  /// Colorize only when stderr is a terminal, honoring the `NO_COLOR` and `CLICOLOR_FORCE` environment
+  /// variables. This is the default.
+  Auto,
+}
+
+#[cfg(feature = "color")]
+/// `0` means "no override, use [`Auto`](ColorChoice::Auto) detection"; `1`/`2`/`3` store `Always`/`Never`/`Auto`.
+static COLOR_CHOICE_OVERRIDE: AtomicU8 = AtomicU8::new(0);
+
+#[cfg(feature = "color")]
+/// Overrides this crate's automatic color detection globally, for the rest of the process.
+///
+/// By default (i.e. without calling this), color is detected as [`ColorChoice::Auto`](ColorChoice::Auto):
+/// honoring the `NO_COLOR` and `CLICOLOR_FORCE` environment variables, then falling back to whether stderr
+/// is a terminal. Call this once at startup if your application wants to force a specific choice instead,
+/// e.g. to respect a `--color` flag.
+pub fn set_color_choice(choice: ColorChoice) {
+  let value = match choice {
+    ColorChoice::Always => 1,
+    ColorChoice::Never => 2,
+    ColorChoice::Auto => 3,
+  };
+  COLOR_CHOICE_OVERRIDE.store(value, Ordering::Relaxed);
+}
+
+#[cfg(feature = "color")]
+/// Resolves the effective [`termcolor::ColorChoice`](termcolor::ColorChoice) for this process: the override
+/// set via [`set_color_choice`](set_color_choice), if any, otherwise `NO_COLOR` / `CLICOLOR_FORCE` / TTY detection.
+///
+/// `Auto` — whether it's the default (no override) or an explicit [`set_color_choice`](set_color_choice)`(`[`ColorChoice::Auto`](ColorChoice::Auto)`)`
+/// — always resolves through the same `NO_COLOR` / `CLICOLOR_FORCE` / TTY detection, so the two behave identically.
+fn resolve_color_choice() -> termcolor::ColorChoice {
+  match COLOR_CHOICE_OVERRIDE.load(Ordering::Relaxed) {
+    1 => return termcolor::ColorChoice::Always,
+    2 => return termcolor::ColorChoice::Never,
+    _ => {}
+  }
+  if std::env::var_os("NO_COLOR").is_some() { return termcolor::ColorChoice::Never; }
+  if std::env::var_os("CLICOLOR_FORCE").is_some_and(|v| v != "0") { return termcolor::ColorChoice::Always; }
+  if !std::io::IsTerminal::is_terminal(&std::io::stderr()) { return termcolor::ColorChoice::Never; }
+  termcolor::ColorChoice::Auto
+}
+
 #[doc(hidden)]
 /// Write the error prefix for the [error!](error) macro.
 ///
 /// This function is internal.
 pub fn internal_write_error_prefix() {
   #[cfg(feature = "color")]
-  if !internal_write_red_error_prefix() { eprint!(get_error_prefix!()); }
+  if !internal_write_red_error_prefix() { internal_write_stderr(format_args!(get_error_prefix!())); }
 
   #[cfg(not(feature = "color"))]
-  eprint!(get_error_prefix!());
+  internal_write_stderr(format_args!(get_error_prefix!()));
 }
 
 #[doc(hidden)]
@@ -67,7 +196,7 @@ pub fn internal_write_error_prefix() {
 /// In other words, if false, we should retry but fallback to normal printing.
 fn internal_write_red_error_prefix() -> bool {
   use std::io::Write;
-  let mut stderr = termcolor::StandardStream::stderr(termcolor::ColorChoice::Auto);
+  let mut stderr = termcolor::StandardStream::stderr(resolve_color_choice());
   if termcolor::WriteColor::set_color(&mut stderr, termcolor::ColorSpec::new().set_fg(Some(termcolor::Color::Red))).is_err() { return false }
   let did_write = write!(&mut stderr, get_error_prefix!()).is_ok();
   termcolor::WriteColor::reset(&mut stderr)
@@ -75,11 +204,57 @@ fn internal_write_red_error_prefix() -> bool {
   did_write
 }
 
+#[doc(hidden)]
+#[cfg(feature = "color")]
+/// The outcome of [`internal_write_colored_error`](internal_write_colored_error), distinguishing "wrote
+/// nothing" from "wrote the prefix" so the caller never re-emits a prefix that already made it to the stream.
+enum ColoredErrorOutcome {
+  /// Prefix and body both printed in color; there's nothing left for the caller to do.
+  Full,
+  /// The prefix printed, but the body didn't; the caller must still write the body (and its trailing
+  /// newline) in plain text — without re-printing the prefix.
+  PrefixOnly,
+  /// Nothing printed (the very first color write failed); the caller should fall back entirely to
+  /// [`internal_write_error_prefix`](internal_write_error_prefix) plus plain body output.
+  Nothing,
+}
+
+#[doc(hidden)]
+#[cfg(feature = "color")]
+/// Writes the whole `error!`/`error_code!` message — the red prefix and the body, in a distinct shade — to
+/// the same [`termcolor`](termcolor) stderr stream, honoring [`resolve_color_choice`](resolve_color_choice).
+///
+/// See [`ColoredErrorOutcome`](ColoredErrorOutcome) for how the caller should react to a partial failure.
+fn internal_write_colored_error(args: std::fmt::Arguments) -> ColoredErrorOutcome {
+  use std::io::Write;
+  let mut stderr = termcolor::StandardStream::stderr(resolve_color_choice());
+  if termcolor::WriteColor::set_color(&mut stderr, termcolor::ColorSpec::new().set_fg(Some(termcolor::Color::Red))).is_err() {
+    return ColoredErrorOutcome::Nothing;
+  }
+  if write!(&mut stderr, get_error_prefix!()).is_err() {
+    return ColoredErrorOutcome::Nothing;
+  }
+  // The prefix made it to the stream — any failure from here on must not be retried from scratch.
+  if termcolor::WriteColor::set_color(&mut stderr, termcolor::ColorSpec::new().set_fg(Some(termcolor::Color::Yellow))).is_err() {
+    termcolor::WriteColor::reset(&mut stderr).ok();
+    return ColoredErrorOutcome::PrefixOnly;
+  }
+  let wrote_body = write!(&mut stderr, "{args}").is_ok();
+  if wrote_body {
+    writeln!(&mut stderr)
+      .ok(); // the body itself is already on the stream; don't let a lost trailing newline trigger a re-print.
+  }
+  termcolor::WriteColor::reset(&mut stderr)
+    .ok(); // ignore any potential error, we passed the point of no return.
+  if wrote_body { ColoredErrorOutcome::Full } else { ColoredErrorOutcome::PrefixOnly }
+}
+
 #[macro_export]
 /// Prints an error message to standard-error and exits with an error code.
 ///
 /// Equivalent to [`fatal!`](fatal), but prefixes the message (when present) with “Error: ”.
-/// If the `color` flag is set, will attempt to color the prefix in red.
+/// If the `color` flag is set, will attempt to color the “Error: ” prefix in red and the rest of the message
+/// in a distinct shade (see [`set_color_choice`](set_color_choice) to override detection).
 ///
 /// # User Experience
 /// The message you write in the arguments is in the middle of a sentence, so you may or may not want to capitalize the beginning (unless it's a proper-noun, of course).
@@ -91,10 +266,65 @@ fn internal_write_red_error_prefix() -> bool {
 /// error!("Bad input") // "Error: Bad input"
 /// ```
 macro_rules! error {
-  () => { $crate::fatal!() };
+  () => { $crate::error_code!(1) };
+  ($($arg:tt)*) => { $crate::error_code!(1, $($arg)*) };
+}
+
+#[macro_export]
+/// Prints an error message to standard-error and exits with the given error code.
+///
+/// Equivalent to [`error!`](error), but lets the caller choose the exit code instead of defaulting to `1`
+/// (see the crate's `EX_*` sysexits-style constants, e.g. [`EX_USAGE`](EX_USAGE), for common choices).
+macro_rules! error_code {
+  ($code:expr) => { $crate::fatal_code!($code) };
+  ($code:expr, $($arg:tt)*) => {
+    {
+      $crate::internal_write_error(::std::format_args!($($arg)*));
+      $crate::fatal_code!($code);
+    }
+  };
+}
+
+#[doc(hidden)]
+/// Writes the body (and, on failure of the colored fast path, the prefix) of an `error!`/`error_code!` message.
+///
+/// When the `color` feature is enabled, this colors the prefix and the body together on the same stderr
+/// stream; otherwise (or if that fails) it falls back to [`internal_write_error_prefix`](internal_write_error_prefix)
+/// plus a plain, uncolored write of `args`.
+pub fn internal_write_error(args: std::fmt::Arguments) {
+  #[cfg(feature = "color")]
+  match internal_write_colored_error(args) {
+    ColoredErrorOutcome::Full => return,
+    ColoredErrorOutcome::PrefixOnly => return internal_write_error_body_plain(args),
+    ColoredErrorOutcome::Nothing => {}
+  }
+
+  internal_write_error_prefix();
+  internal_write_error_body_plain(args);
+}
+
+#[doc(hidden)]
+/// Writes `args` followed by a newline, as plain (uncolored) output.
+fn internal_write_error_body_plain(args: std::fmt::Arguments) {
+  internal_write_stderr(args);
+  internal_write_stderr(format_args!("\n"));
+}
+
+#[macro_export]
+/// Reports an internal/programmer error — one that indicates a bug in this code rather than bad user input — and exits.
+///
+/// Equivalent to [`error!`](error), but the message is additionally prefixed with the call site's `file!`/`line!`
+/// (e.g. `"src/main.rs:42: internal error: <msg>"`), the way the `userror` crate does. The location is written
+/// via a separate [`format_args!`](::std::format_args!) built from `file!`/`line!` at the macro site, so the
+/// message itself (the `$fmt`) is free to be any expression `fatal!` accepts — not just a literal.
+///
+/// Use this to distinguish "you did something wrong" ([`error!`](error)) from "I did something wrong" (this macro).
+macro_rules! bug {
+  () => { $crate::bug!("reached unreachable code") };
   ($($arg:tt)*) => {
     {
       $crate::internal_write_error_prefix();
+      $crate::internal_write_stderr(::std::format_args!("{}:{}: internal error: ", ::std::file!(), ::std::line!()));
       $crate::fatal!($($arg)*);
     }
   };
@@ -122,6 +352,15 @@ pub fn expect<T,E: Display>(result: Result<T,E>, message: impl Display) -> T {
   result.unwrap_or_else(|e| error!("{} ({})", message, e))
 }
 
+/// Unwraps the result or reports the given message with the error and exits with the given error code.
+///
+/// The error is reported with [`error_code!`](error_code).
+///
+/// See [`UnwrapExt`](UnwrapExt) for an extension trait version.
+pub fn expect_code<T,E: Display>(result: Result<T,E>, code: i32, message: impl Display) -> T {
+  result.unwrap_or_else(|e| error_code!(code, "{} ({})", message, e))
+}
+
 /// An extension trait for [`unwrap`](unwrap).
 pub trait UnwrapExt {
   type T;
@@ -131,12 +370,61 @@ pub trait UnwrapExt {
 
   /// An extension synonym for [`expect`](expect).
   fn expect_fatal(self, message: impl Display) -> Self::T;
+
+  /// An extension synonym for [`expect_code`](expect_code).
+  fn expect_code_fatal(self, code: i32, message: impl Display) -> Self::T;
 }
 
 impl<T,E: Display> UnwrapExt for Result<T,E> {
   type T = T;
   fn unwrap_fatal(self) -> Self::T { unwrap(self) }
   fn expect_fatal(self, message: impl Display) -> Self::T { expect(self, message) }
+  fn expect_code_fatal(self, code: i32, message: impl Display) -> Self::T { expect_code(self, code, message) }
+}
+
+/// The maximum number of [`source`](std::error::Error::source) hops [`unwrap_chain`](unwrap_chain) will walk.
+///
+/// This guards against malformed error chains that cycle back on themselves instead of terminating.
+const MAX_CHAIN_DEPTH: usize = 64;
+
+/// Unwraps a result or reports its error, followed by its full [`source`](std::error::Error::source) chain, and exits.
+///
+/// Unlike [`unwrap`](unwrap), which only prints the error's [`Display`](Display) output, this walks
+/// `err.source()` and prints each underlying cause on its own line, so wrapped/layered errors
+/// (e.g. a parse error wrapping an I/O error) don't lose their intermediate causes.
+///
+/// See [`UnwrapChainExt`](UnwrapChainExt) for an extension trait version.
+pub fn unwrap_chain<T,E: std::error::Error>(result: Result<T,E>) -> T {
+  result.unwrap_or_else(|e| {
+    internal_write_error_prefix();
+    internal_write_stderr(format_args!("{}\n", e));
+    let mut src = std::error::Error::source(&e);
+    let mut depth = 0;
+    let mut seen = Vec::with_capacity(MAX_CHAIN_DEPTH);
+    while let Some(e) = src {
+      if depth >= MAX_CHAIN_DEPTH { break; }
+      let ptr = e as *const dyn std::error::Error as *const ();
+      if seen.contains(&ptr) { break; }
+      seen.push(ptr);
+      internal_write_stderr(format_args!("  caused by: {e}\n"));
+      src = e.source();
+      depth += 1;
+    }
+    fatal!()
+  })
+}
+
+/// An extension trait for [`unwrap_chain`](unwrap_chain).
+pub trait UnwrapChainExt {
+  type T;
+
+  /// An extension synonym for [`unwrap_chain`](unwrap_chain).
+  fn unwrap_chain_fatal(self) -> Self::T;
+}
+
+impl<T,E: std::error::Error> UnwrapChainExt for Result<T,E> {
+  type T = T;
+  fn unwrap_chain_fatal(self) -> Self::T { unwrap_chain(self) }
 }
 
 #[macro_export]
@@ -171,6 +459,83 @@ macro_rules! unwrap_message {
   };
 }
 
+/// Unwraps the result or reports it as an internal/programmer bug (with file/line) and exits.
+///
+/// This is like [`unwrap_message`](unwrap_message) but reports via [`bug!`](bug) instead of [`error!`](error).
+#[macro_export]
+macro_rules! unwrap_bug {
+  ($result:expr) => {
+    $result.unwrap_or_else(|e| $crate::bug!("{error}", error=e))
+  };
+  ($result:expr, $msg:tt) => {
+    $result.unwrap_or_else(|e| $crate::bug!(::std::concat!($msg, " ({error})"), error=e))
+  };
+  ($result:expr, $msg:tt, $($param:tt)*) => {
+    $result.unwrap_or_else(|e| $crate::bug!(::std::concat!($msg, " ({error})"), $($param)*, error=e))
+  };
+}
+
+/// Accumulates [`Result`](Result)s so every failure can be reported together, rather than aborting on the
+/// first one as [`unwrap`](unwrap)/[`expect`](expect) do.
+///
+/// Handy when validating many independent inputs (config keys, CLI args, a batch of files), where printing
+/// every problem up front is friendlier than a single abort-on-first-failure pass.
+///
+/// See [`unwrap_all`](unwrap_all) for a one-shot convenience over an iterator of [`Result`](Result)s.
+pub struct Collector {
+  errors: Vec<Box<dyn Display>>,
+}
+
+impl Collector {
+  /// Creates an empty collector.
+  pub fn new() -> Self {
+    Self { errors: Vec::new() }
+  }
+
+  /// Records `result`'s error, if any, discarding the [`Ok`](Ok) value.
+  ///
+  /// See [`Collector::r#try`](Collector::r#try) to keep the [`Ok`](Ok) value instead.
+  pub fn push<T, E: Display + 'static>(&mut self, result: Result<T, E>) {
+    self.r#try(result);
+  }
+
+  /// Records `result`'s error, if any, and returns its [`Ok`](Ok) value, if any.
+  pub fn r#try<T, E: Display + 'static>(&mut self, result: Result<T, E>) -> Option<T> {
+    match result {
+      Ok(value) => Some(value),
+      Err(e) => {
+        self.errors.push(Box::new(e));
+        None
+      }
+    }
+  }
+
+  /// If any errors were recorded, reports them all under an [`error!`](error)-styled header
+  /// (e.g. `"Error: 3 problems:"`) and exits with code `1`. Otherwise, simply returns.
+  pub fn finish(self) {
+    if self.errors.is_empty() { return; }
+    internal_write_error_prefix();
+    internal_write_stderr(format_args!("{} problem{}:\n", self.errors.len(), if self.errors.len() == 1 { "" } else { "s" }));
+    for e in &self.errors {
+      internal_write_stderr(format_args!("  {e}\n"));
+    }
+    fatal!()
+  }
+}
+
+impl Default for Collector {
+  fn default() -> Self { Self::new() }
+}
+
+/// Unwraps every [`Result`](Result) in `iter`, returning a [`Vec`](Vec) of all [`Ok`](Ok) values if every one
+/// succeeded, or reporting every failure together (via [`Collector`](Collector)) and exiting otherwise.
+pub fn unwrap_all<T, E: Display + 'static, I: IntoIterator<Item = Result<T, E>>>(iter: I) -> Vec<T> {
+  let mut collector = Collector::new();
+  let values: Vec<T> = iter.into_iter().filter_map(|result| collector.r#try(result)).collect();
+  collector.finish();
+  values
+}
+
 #[cfg(test)]
 mod test {
   use super::*;
@@ -188,12 +553,28 @@ mod test {
     unwrap_message!(r, "Error {error}");
     unwrap_message!(r, "Err{} {error}", "or");
 
+    unwrap_bug!(r);
+    unwrap_bug!(r, "Error");
+    unwrap_bug!(r, "Err{} {error}", "or");
+
     error!();
     error!("Error");
     error!("Err{}", "or");
 
+    bug!();
+    bug!("Error");
+    bug!("Err{}", "or");
+
     fatal!();
     fatal!("Error");
     fatal!("Err{}", "or");
+
+    error_code!(64);
+    error_code!(64, "Error");
+    error_code!(64, "Err{}", "or");
+
+    fatal_code!(64);
+    fatal_code!(64, "Error");
+    fatal_code!(64, "Err{}", "or");
   }
 }
\ No newline at end of file